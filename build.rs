@@ -0,0 +1,11 @@
+//! 构建脚本：在 Windows 目标上嵌入 PerMonitorV2 DPI 感知清单，
+//! 避免系统对窗口表面做位图拉伸。
+
+fn main() {
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os == "windows" {
+        let mut res = winres::WindowsResource::new();
+        res.set_manifest_file("assets/windows/app.manifest");
+        res.compile().expect("嵌入 Windows 应用清单失败");
+    }
+}
@@ -0,0 +1,144 @@
+//! 在 egui 渲染之前跑一次独立的 wgpu 渲染通道，绘制自定义几何图形，
+//! 展示原生 GPU 绘制和即时模式 UI 如何共享同一张 surface。
+
+use egui_wgpu::wgpu;
+use winit::dpi::PhysicalSize;
+
+/// 背景渲染器统一接口：在 egui 之前用 `LoadOp::Clear` 画一层自定义内容，
+/// egui 随后以 `LoadOp::Load` 叠加在其上。
+pub trait BackgroundRenderer {
+    fn render(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        queue: &wgpu::Queue,
+        size: PhysicalSize<u32>,
+        time: f32,
+    );
+}
+
+/// `Uniforms` 在 WGSL 里只有一个 `f32` 字段，但 uniform 地址空间的最小绑定
+/// 大小是 16 字节，所以缓冲区和每帧写入都按 16 字节走，多余部分留空。
+const UNIFORM_BUFFER_SIZE: u64 = 16;
+
+/// 用一个全屏三角形画的动画渐变，时间由每帧累计的 `dt` 驱动。
+pub struct GradientBackground {
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    uniform_buffer: wgpu::Buffer,
+}
+
+impl GradientBackground {
+    pub fn new(device: &wgpu::Device, surface_format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("background-gradient-shader"),
+            source: wgpu::ShaderSource::Wgsl(
+                include_str!("../assets/shaders/background_gradient.wgsl").into(),
+            ),
+        });
+
+        // uniform 地址空间的结构体按 16 字节对齐，即便只有一个 f32 字段也要留够空间
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("background-gradient-uniforms"),
+            size: UNIFORM_BUFFER_SIZE,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("background-gradient-bind-group-layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: wgpu::BufferSize::new(UNIFORM_BUFFER_SIZE),
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("background-gradient-bind-group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("background-gradient-pipeline-layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("background-gradient-pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group,
+            uniform_buffer,
+        }
+    }
+}
+
+impl BackgroundRenderer for GradientBackground {
+    fn render(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        queue: &wgpu::Queue,
+        _size: PhysicalSize<u32>,
+        time: f32,
+    ) {
+        let mut uniform_bytes = [0u8; UNIFORM_BUFFER_SIZE as usize];
+        uniform_bytes[0..4].copy_from_slice(&time.to_le_bytes());
+        queue.write_buffer(&self.uniform_buffer, 0, &uniform_bytes);
+
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("background-pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 0.0,
+                        g: 0.0,
+                        b: 0.0,
+                        a: 1.0,
+                    }),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
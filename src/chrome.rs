@@ -0,0 +1,97 @@
+//! 可选的无边框窗口模式：自绘标题栏 + 边缘/角落命中测试，
+//! 通过 winit 的拖拽 API 实现移动和缩放。
+
+use egui::{Align, Layout};
+use winit::dpi::PhysicalPosition;
+use winit::window::{CursorIcon, ResizeDirection, Window};
+
+/// 鼠标距离窗口边缘多少像素以内算作“在边框上”，用于触发缩放。
+const RESIZE_BORDER_PX: f64 = 8.0;
+
+/// 自绘标题栏与无边框模式的开关，通过环境变量 `LEARN_EGUI_BORDERLESS=1` 开启。
+pub struct CustomChrome {
+    pub enabled: bool,
+}
+
+impl CustomChrome {
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("LEARN_EGUI_BORDERLESS").as_deref() == Ok("1");
+        Self { enabled }
+    }
+
+    /// 在无边框模式下绘制自定义标题栏：左侧标题文字，右侧最小化/关闭按钮，
+    /// 其余区域作为拖拽把手，按下并拖动即可移动窗口。
+    pub fn ui(&self, ctx: &egui::Context, window: &Window) {
+        if !self.enabled {
+            return;
+        }
+
+        egui::TopBottomPanel::top("custom_title_bar")
+            .exact_height(32.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("winit + egui 计数器");
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui.button("✕").clicked() {
+                            std::process::exit(0);
+                        }
+                        if ui.button("—").clicked() {
+                            window.set_minimized(true);
+                        }
+
+                        // 剩余空间作为标题栏拖拽把手
+                        let drag_rect = ui.available_rect_before_wrap();
+                        let response = ui.interact(
+                            drag_rect,
+                            ui.id().with("titlebar_drag"),
+                            egui::Sense::drag(),
+                        );
+                        if response.drag_started() {
+                            let _ = window.drag_window();
+                        }
+                    });
+                });
+            });
+    }
+}
+
+/// 判断鼠标位置落在窗口哪个边缘/角落上，返回对应的缩放方向；不在边框上则为 `None`。
+pub fn resize_direction_at(
+    window: &Window,
+    cursor: PhysicalPosition<f64>,
+) -> Option<ResizeDirection> {
+    let size = window.inner_size();
+    let (w, h) = (size.width as f64, size.height as f64);
+    let (x, y) = (cursor.x, cursor.y);
+
+    let on_left = x <= RESIZE_BORDER_PX;
+    let on_right = x >= w - RESIZE_BORDER_PX;
+    let on_top = y <= RESIZE_BORDER_PX;
+    let on_bottom = y >= h - RESIZE_BORDER_PX;
+
+    match (on_left, on_right, on_top, on_bottom) {
+        (true, _, true, _) => Some(ResizeDirection::NorthWest),
+        (_, true, true, _) => Some(ResizeDirection::NorthEast),
+        (true, _, _, true) => Some(ResizeDirection::SouthWest),
+        (_, true, _, true) => Some(ResizeDirection::SouthEast),
+        (true, false, false, false) => Some(ResizeDirection::West),
+        (false, true, false, false) => Some(ResizeDirection::East),
+        (false, false, true, false) => Some(ResizeDirection::North),
+        (false, false, false, true) => Some(ResizeDirection::South),
+        _ => None,
+    }
+}
+
+/// 缩放方向对应的鼠标光标样式，用于命中边框时给用户视觉提示。
+pub fn cursor_icon_for(direction: ResizeDirection) -> CursorIcon {
+    match direction {
+        ResizeDirection::North => CursorIcon::NResize,
+        ResizeDirection::NorthEast => CursorIcon::NeResize,
+        ResizeDirection::East => CursorIcon::EResize,
+        ResizeDirection::SouthEast => CursorIcon::SeResize,
+        ResizeDirection::South => CursorIcon::SResize,
+        ResizeDirection::SouthWest => CursorIcon::SwResize,
+        ResizeDirection::West => CursorIcon::WResize,
+        ResizeDirection::NorthWest => CursorIcon::NwResize,
+    }
+}
@@ -0,0 +1,176 @@
+//! 应用内日志控制台：有界环形缓冲区 + 自定义 `log::Log` 实现，
+//! 把 `env_logger` 原本只写 stderr 的输出接入 UI，变成可滚动查看的诊断面板。
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// 控制台最多保留的行数，超出后淘汰最旧的一行。
+const MAX_LINES: usize = 500;
+
+#[derive(Clone)]
+struct LogLine {
+    level: log::Level,
+    message: String,
+}
+
+/// 日志线程与 UI 线程共享的环形缓冲区；日志侧只管往里追加，
+/// UI 侧每帧只拷贝自上次消费以来新增的那部分。
+#[derive(Clone)]
+struct SharedRing(Arc<Mutex<RingInner>>);
+
+struct RingInner {
+    lines: VecDeque<LogLine>,
+    total_pushed: u64,
+}
+
+impl SharedRing {
+    fn new() -> Self {
+        Self(Arc::new(Mutex::new(RingInner {
+            lines: VecDeque::with_capacity(MAX_LINES),
+            total_pushed: 0,
+        })))
+    }
+
+    fn push(&self, line: LogLine) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.lines.len() == MAX_LINES {
+            inner.lines.pop_front();
+        }
+        inner.lines.push_back(line);
+        inner.total_pushed += 1;
+    }
+
+    /// 取出自 `last_seen` 之后新增的行，并把 `last_seen` 推进到最新。
+    fn drain_new(&self, last_seen: &mut u64) -> Vec<LogLine> {
+        let inner = self.0.lock().unwrap();
+        let missed = inner.total_pushed.saturating_sub(*last_seen);
+        let available = inner.lines.len() as u64;
+        let take = missed.min(available) as usize;
+        let new_lines = inner.lines.iter().rev().take(take).rev().cloned().collect();
+        *last_seen = inner.total_pushed;
+        new_lines
+    }
+}
+
+/// 把日志记录同时转发给 `env_logger`（stderr）和应用内的环形缓冲区。
+struct RingLogger {
+    env_logger: env_logger::Logger,
+    ring: SharedRing,
+}
+
+impl log::Log for RingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.env_logger.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.ring.push(LogLine {
+                level: record.level(),
+                message: format!("{}", record.args()),
+            });
+        }
+        self.env_logger.log(record);
+    }
+
+    fn flush(&self) {
+        self.env_logger.flush();
+    }
+}
+
+/// UI 侧的日志控制台：固定容量的环形缓冲区、自动滚动开关、显示过滤级别。
+pub struct LogConsole {
+    lines: VecDeque<LogLine>,
+    auto_scroll: bool,
+    filter: log::LevelFilter,
+    shared: SharedRing,
+    last_seen: u64,
+}
+
+impl LogConsole {
+    fn new(shared: SharedRing) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(MAX_LINES),
+            auto_scroll: true,
+            filter: log::LevelFilter::Trace,
+            shared,
+            last_seen: 0,
+        }
+    }
+
+    fn pull_new_lines(&mut self) {
+        for line in self.shared.drain_new(&mut self.last_seen) {
+            if self.lines.len() == MAX_LINES {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(line);
+        }
+    }
+
+    pub fn ui(&mut self, ctx: &egui::Context) {
+        self.pull_new_lines();
+
+        egui::TopBottomPanel::bottom("log_console")
+            .resizable(true)
+            .default_height(180.0)
+            .show(ctx, |ui| {
+                ui.collapsing("日志控制台", |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("最低级别:");
+                        egui::ComboBox::from_id_source("log_console_filter")
+                            .selected_text(self.filter.to_string())
+                            .show_ui(ui, |ui| {
+                                for level in [
+                                    log::LevelFilter::Error,
+                                    log::LevelFilter::Warn,
+                                    log::LevelFilter::Info,
+                                    log::LevelFilter::Debug,
+                                    log::LevelFilter::Trace,
+                                ] {
+                                    ui.selectable_value(&mut self.filter, level, level.to_string());
+                                }
+                            });
+                        ui.checkbox(&mut self.auto_scroll, "自动滚动到底部");
+                        if ui.button("清空").clicked() {
+                            self.lines.clear();
+                        }
+                    });
+
+                    let mut scroll_area = egui::ScrollArea::vertical().max_height(140.0);
+                    if self.auto_scroll {
+                        scroll_area = scroll_area.stick_to_bottom(true);
+                    }
+                    scroll_area.show(ui, |ui| {
+                        for line in self.lines.iter().filter(|l| l.level <= self.filter) {
+                            ui.colored_label(color_for_level(line.level), &line.message);
+                        }
+                    });
+                });
+            });
+    }
+}
+
+fn color_for_level(level: log::Level) -> egui::Color32 {
+    match level {
+        log::Level::Error => egui::Color32::from_rgb(235, 90, 90),
+        log::Level::Warn => egui::Color32::from_rgb(235, 190, 90),
+        log::Level::Info => egui::Color32::from_rgb(150, 200, 255),
+        log::Level::Debug => egui::Color32::GRAY,
+        log::Level::Trace => egui::Color32::DARK_GRAY,
+    }
+}
+
+/// 安装日志记录器（同时写 stderr 和应用内环形缓冲区），返回可挂到 UI 上的控制台。
+pub fn init() -> LogConsole {
+    let ring = SharedRing::new();
+    let env_logger = env_logger::Builder::from_default_env().build();
+    let max_level = env_logger.filter();
+    let logger = RingLogger {
+        env_logger,
+        ring: ring.clone(),
+    };
+    log::set_boxed_logger(Box::new(logger)).expect("安装日志记录器失败");
+    log::set_max_level(max_level);
+
+    LogConsole::new(ring)
+}
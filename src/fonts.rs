@@ -0,0 +1,232 @@
+//! 跨平台字体发现：为 egui 安装一套有序的中文字体回退链。
+//!
+//! 解析顺序：
+//! 1. 用户通过环境变量 `LEARN_EGUI_FONT_PATH` 指定的字体文件；
+//! 2. 当前平台的标准字体目录（按候选字体族名匹配）；
+//! 3. 编译进二进制的内置字体 `assets/fonts/NotoSansSC-Regular.otf`。
+//!
+//! 内置字体同样先做魔数校验；它若不是有效字体，则不安装任何字体，
+//! 保留 egui 的默认设置并返回 `FontSource::Unavailable`，此时中文会显示为方块。
+
+use std::path::{Path, PathBuf};
+
+use egui::{FontData, FontDefinitions, FontFamily};
+
+/// 内置回退字体，打包进可执行文件，作为最后一道保险。
+const EMBEDDED_FONT_BYTES: &[u8] = include_bytes!("../assets/fonts/NotoSansSC-Regular.otf");
+
+/// 记录字体最终来自哪里，便于调用方打日志排查“为什么还是方块”。
+#[derive(Debug, Clone)]
+pub enum FontSource {
+    /// 环境变量 `LEARN_EGUI_FONT_PATH` 指定的路径。
+    UserConfigured(PathBuf),
+    /// 在系统字体目录中找到的匹配字体。
+    System(PathBuf),
+    /// 内置回退字体。
+    Embedded,
+    /// 所有来源（含内置回退）都不可用，保留了 egui 的默认字体设置。
+    Unavailable,
+}
+
+impl std::fmt::Display for FontSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontSource::UserConfigured(path) => write!(f, "用户指定字体 ({})", path.display()),
+            FontSource::System(path) => write!(f, "系统字体 ({})", path.display()),
+            FontSource::Embedded => write!(f, "内置回退字体"),
+            FontSource::Unavailable => write!(f, "无可用字体，已保留 egui 默认设置"),
+        }
+    }
+}
+
+/// 为 egui 安装中文字体回退，并返回实际生效的字体来源。
+///
+/// 只要能解析出一份看起来有效的字体数据，就会在 Proportional / Monospace
+/// 两个字族前插入它，不会像旧实现那样在找不到系统字体时静默保留默认设置。
+/// 但如果连内置的最后一道保险都不是有效字体（比如打包时出了问题），也不会
+/// 把这份坏数据喂给 `ctx.set_fonts`——egui 在解析阶段会直接 panic，这里选择
+/// 保留 egui 的默认字体设置，让程序至少能跑起来。
+pub fn install_cjk_fonts(ctx: &egui::Context) -> FontSource {
+    let Some((bytes, source)) = resolve_font() else {
+        log::error!("内置回退字体数据无效，保留 egui 默认字体设置（中文可能显示为方块）");
+        return FontSource::Unavailable;
+    };
+
+    let mut fonts = FontDefinitions::default();
+    let key = "cjk-fallback".to_owned();
+    fonts.font_data.insert(key.clone(), FontData::from_owned(bytes));
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .insert(0, key.clone());
+    fonts
+        .families
+        .entry(FontFamily::Monospace)
+        .or_default()
+        .insert(0, key);
+    ctx.set_fonts(fonts);
+
+    source
+}
+
+/// 按“用户配置 -> 系统目录 -> 内置字体”的顺序解析出一份可用字体数据；
+/// 每一步都先校验字体魔数，解析不出来就继续往下一个来源走。
+fn resolve_font() -> Option<(Vec<u8>, FontSource)> {
+    if let Some(path) = user_configured_path() {
+        match std::fs::read(&path) {
+            Ok(bytes) if looks_like_font(&bytes) => {
+                return Some((bytes, FontSource::UserConfigured(path)));
+            }
+            Ok(_) => log::warn!(
+                "LEARN_EGUI_FONT_PATH 指向的文件不是有效字体，忽略: {}",
+                path.display()
+            ),
+            Err(_) => log::warn!("LEARN_EGUI_FONT_PATH 指向的字体读取失败: {}", path.display()),
+        }
+    }
+
+    if let Some((bytes, path)) = find_system_font() {
+        return Some((bytes, FontSource::System(path)));
+    }
+
+    if looks_like_font(EMBEDDED_FONT_BYTES) {
+        Some((EMBEDDED_FONT_BYTES.to_vec(), FontSource::Embedded))
+    } else {
+        None
+    }
+}
+
+/// 粗略校验字节是否像一份 TTF/OTF/TTC 字体：只看文件头魔数，不做完整解析，
+/// 但足以挡住占位文件或损坏文件，避免把它们交给 egui 后在 `set_fonts` 里 panic。
+fn looks_like_font(bytes: &[u8]) -> bool {
+    const MAGICS: [[u8; 4]; 5] = [
+        [0x00, 0x01, 0x00, 0x00], // TrueType
+        *b"OTTO",                 // OpenType（CFF 轮廓）
+        *b"true",                 // Apple TrueType
+        *b"typ1",                 // 旧式 PostScript Type 1 包装
+        *b"ttcf",                 // TrueType/OpenType 合集
+    ];
+    match bytes.get(0..4) {
+        Some(magic) => MAGICS.iter().any(|m| m.as_slice() == magic),
+        None => false,
+    }
+}
+
+fn user_configured_path() -> Option<PathBuf> {
+    std::env::var_os("LEARN_EGUI_FONT_PATH").map(PathBuf::from)
+}
+
+/// 在当前平台的标准字体目录中，按候选字体族名的优先级顺序查找匹配的文件。
+/// 候选名在前的字体族（如 Windows 上的 msyh）优先于排在后面的（如 simsun），
+/// 这与目录下文件被 `read_dir` 枚举到的顺序无关。
+fn find_system_font() -> Option<(Vec<u8>, PathBuf)> {
+    let font_files = collect_font_files();
+    for candidate in font_name_candidates() {
+        let candidate = candidate.to_lowercase();
+        let matched = font_files.iter().find(|path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|stem| stem.to_lowercase().contains(&candidate))
+        });
+        let Some(matched) = matched else { continue };
+        match std::fs::read(matched) {
+            Ok(bytes) if looks_like_font(&bytes) => return Some((bytes, matched.clone())),
+            Ok(_) => log::warn!("系统字体文件内容异常，跳过: {}", matched.display()),
+            Err(_) => {}
+        }
+    }
+    None
+}
+
+/// 当前平台的标准字体安装目录。
+fn font_directories() -> Vec<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        let windir = std::env::var_os("WINDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("C:/Windows"));
+        vec![windir.join("Fonts")]
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        vec![
+            PathBuf::from("/System/Library/Fonts"),
+            PathBuf::from("/Library/Fonts"),
+        ]
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        let mut dirs = vec![PathBuf::from("/usr/share/fonts")];
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(PathBuf::from(home).join(".fonts"));
+        }
+        dirs
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", unix)))]
+    {
+        Vec::new()
+    }
+}
+
+/// 候选字体族名（按平台常见的中文字体命名）。
+fn font_name_candidates() -> &'static [&'static str] {
+    #[cfg(target_os = "windows")]
+    {
+        &[
+            "msyh", "msyhbd", "simhei", "simsun", "deng", "msjh", "nsimsun",
+        ]
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        &["pingfang", "hiragino", "notosanscjk", "noto sans cjk"]
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        &["noto sans cjk", "notosanscjk", "wqy", "wenquanyi", "source han", "sourcehan"]
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", unix)))]
+    {
+        &[]
+    }
+}
+
+/// 递归收集当前平台标准字体目录下的所有字体文件路径（不做名称过滤，
+/// 过滤交给 `find_system_font` 按候选优先级处理）。
+fn collect_font_files() -> Vec<PathBuf> {
+    const MAX_DEPTH: u32 = 4;
+    let mut files = Vec::new();
+    for dir in font_directories() {
+        collect_font_files_inner(&dir, MAX_DEPTH, &mut files);
+    }
+    files
+}
+
+fn collect_font_files_inner(dir: &Path, depth: u32, files: &mut Vec<PathBuf>) {
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_font_files_inner(&path, depth - 1, files);
+            continue;
+        }
+        let is_font_file = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("ttf" | "ttc" | "otf" | "otc")
+        );
+        if is_font_file {
+            files.push(path);
+        }
+    }
+}
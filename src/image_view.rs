@@ -0,0 +1,160 @@
+//! 图片加载与显示：把磁盘上的 PNG/JPEG 解码为 RGBA8，上传成 wgpu 纹理，
+//! 再通过 egui::Image 展示出来。复用 EguiRenderer 管理自身纹理图集的那套
+//! register/free 机制来管理用户加载的图片。
+
+use egui_wgpu::{Renderer as EguiRenderer, wgpu};
+
+/// 解码后的位图：宽高 + 按行紧凑排列的 RGBA8 像素。
+pub struct DecodedImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+/// 将 PNG/JPEG 等常见格式的原始字节解码为 RGBA8。
+pub fn decode_rgba8(bytes: &[u8]) -> image::ImageResult<DecodedImage> {
+    let rgba = image::load_from_memory(bytes)?.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    Ok(DecodedImage {
+        width,
+        height,
+        pixels: rgba.into_raw(),
+    })
+}
+
+struct LoadedImage {
+    texture_id: egui::TextureId,
+    size: egui::Vec2,
+}
+
+/// 图片查看器：持有当前加载的纹理，以及缩放/适应窗口控制项。
+pub struct ImageViewer {
+    image: Option<LoadedImage>,
+    zoom: f32,
+    fit_to_window: bool,
+}
+
+impl Default for ImageViewer {
+    fn default() -> Self {
+        Self {
+            image: None,
+            zoom: 1.0,
+            fit_to_window: true,
+        }
+    }
+}
+
+impl ImageViewer {
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        renderer: &mut EguiRenderer,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
+        ui.horizontal(|ui| {
+            if ui.button("打开图片").clicked() {
+                if let Some(path) = rfd::FileDialog::new()
+                    .add_filter("图片", &["png", "jpg", "jpeg"])
+                    .pick_file()
+                {
+                    self.load_from_path(&path, renderer, device, queue);
+                }
+            }
+            ui.checkbox(&mut self.fit_to_window, "适应窗口");
+            ui.add_enabled(
+                !self.fit_to_window,
+                egui::Slider::new(&mut self.zoom, 0.1..=4.0).text("缩放"),
+            );
+        });
+
+        match &self.image {
+            Some(loaded) => {
+                let available = ui.available_size();
+                let scale = if self.fit_to_window {
+                    (available.x / loaded.size.x)
+                        .min(available.y / loaded.size.y)
+                        .min(1.0)
+                        .max(0.01)
+                } else {
+                    self.zoom
+                };
+                let display_size = loaded.size * scale;
+                egui::ScrollArea::both().show(ui, |ui| {
+                    ui.add(egui::Image::new((loaded.texture_id, display_size)));
+                });
+            }
+            None => {
+                ui.label("尚未加载图片");
+            }
+        }
+    }
+
+    fn load_from_path(
+        &mut self,
+        path: &std::path::Path,
+        renderer: &mut EguiRenderer,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
+        let decoded = std::fs::read(path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| decode_rgba8(&bytes).map_err(|err| err.to_string()));
+
+        match decoded {
+            Ok(decoded) => self.replace(decoded, renderer, device, queue),
+            Err(err) => log::warn!("加载图片 {} 失败: {err}", path.display()),
+        }
+    }
+
+    /// 上传新纹理，并在替换旧图片时释放其纹理，避免纹理泄漏。
+    fn replace(
+        &mut self,
+        decoded: DecodedImage,
+        renderer: &mut EguiRenderer,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
+        let size = wgpu::Extent3d {
+            width: decoded.width,
+            height: decoded.height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("user-image"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &decoded.pixels,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * decoded.width),
+                rows_per_image: Some(decoded.height),
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let texture_id = renderer.register_native_texture(device, &view, wgpu::FilterMode::Linear);
+
+        if let Some(old) = self.image.take() {
+            renderer.free_texture(&old.texture_id);
+        }
+        self.image = Some(LoadedImage {
+            texture_id,
+            size: egui::vec2(decoded.width as f32, decoded.height as f32),
+        });
+    }
+}
@@ -16,33 +16,90 @@ use winit::event::{Event, WindowEvent};
 use winit::event_loop::{ControlFlow, EventLoop};
 use winit::window::{Window, WindowBuilder};
 
-// 应用状态：简单计数器
+mod background;
+mod chrome;
+mod console;
+mod fonts;
+mod image_view;
+
+// 应用状态：简单计数器 + 图片查看器
 struct CounterApp {
     count: i32,
+    image_viewer: image_view::ImageViewer,
 }
 
 impl Default for CounterApp {
     fn default() -> Self {
-        Self { count: 0 }
+        Self {
+            count: 0,
+            image_viewer: image_view::ImageViewer::default(),
+        }
     }
 }
 
 impl CounterApp {
-    fn ui(&mut self, ctx: &EguiContext) {
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    fn decrement(&mut self) {
+        self.count -= 1;
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// 处理键盘输入：方向键/加减号调整计数，R 或 Esc 重置。
+    /// 仅在 egui 没有消费该按键时才生效，避免抢走未来文本框的输入。
+    fn handle_key(&mut self, key_event: &winit::event::KeyEvent, consumed: bool) {
+        use winit::event::ElementState;
+        use winit::keyboard::{Key, NamedKey};
+
+        if consumed || key_event.state != ElementState::Pressed {
+            return;
+        }
+
+        match &key_event.logical_key {
+            Key::Named(NamedKey::ArrowUp) => self.increment(),
+            Key::Named(NamedKey::ArrowDown) => self.decrement(),
+            Key::Named(NamedKey::Escape) => self.reset(),
+            Key::Character(c) => match c.as_str() {
+                "+" | "=" => self.increment(),
+                "-" => self.decrement(),
+                "r" | "R" => self.reset(),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn ui(
+        &mut self,
+        ctx: &EguiContext,
+        egui_renderer: &mut EguiRenderer,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("winit + egui 计数器");
             ui.horizontal(|ui| {
                 if ui.button("-1").clicked() {
-                    self.count -= 1;
+                    self.decrement();
                 }
                 if ui.button("+1").clicked() {
-                    self.count += 1;
+                    self.increment();
                 }
                 if ui.button("重置").clicked() {
-                    self.count = 0;
+                    self.reset();
                 }
             });
             ui.label(format!("当前计数: {}", self.count));
+
+            ui.separator();
+            ui.collapsing("图片查看器", |ui| {
+                self.image_viewer.ui(ui, egui_renderer, device, queue);
+            });
         });
     }
 }
@@ -150,13 +207,18 @@ impl<'w> GfxState<'w> {
 }
 
 fn main() {
-    env_logger::init();
+    // 同时接管 stderr 输出和应用内的日志控制台
+    let mut log_console = console::init();
+
+    // 无边框自绘标题栏模式通过环境变量开启，默认使用系统原生装饰
+    let chrome = chrome::CustomChrome::from_env();
 
     // 事件循环与窗口
     let event_loop = EventLoop::new().expect("创建事件循环失败");
     let window = WindowBuilder::new()
         .with_title("learn_egui - 计数器")
         .with_inner_size(PhysicalSize::new(900, 600))
+        .with_decorations(!chrome.enabled)
         .build(&event_loop)
         .expect("创建窗口失败");
 
@@ -164,7 +226,8 @@ fn main() {
     let mut gfx = pollster::block_on(GfxState::new(&window));
     let egui_ctx = EguiContext::default();
     // 安装中文字体回退，避免 UI 中文显示为方块
-    install_cjk_fonts(&egui_ctx);
+    let font_source = fonts::install_cjk_fonts(&egui_ctx);
+    log::info!("已安装字体: {font_source}");
     let mut egui_winit = EguiWinitState::new(
         egui_ctx.clone(),
         ViewportId::ROOT,
@@ -173,8 +236,12 @@ fn main() {
         None,
     );
     let mut egui_renderer = EguiRenderer::new(&gfx.device, gfx.surface_config.format, None, 1);
+    let mut background: Box<dyn background::BackgroundRenderer> =
+        Box::new(background::GradientBackground::new(&gfx.device, gfx.surface_config.format));
     let mut app = CounterApp::default();
     let mut last_frame = Instant::now();
+    let mut elapsed_time: f32 = 0.0;
+    let mut cursor_pos = winit::dpi::PhysicalPosition::new(0.0_f64, 0.0_f64);
 
     event_loop
         .run(|event, elwt| {
@@ -190,18 +257,49 @@ fn main() {
                     match event {
                         WindowEvent::CloseRequested => elwt.exit(),
                         WindowEvent::Resized(size) => gfx.resize(size),
-                        WindowEvent::ScaleFactorChanged { .. } => {
+                        WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                            // 新显示器的缩放比例要同时喂给 egui，否则文字在跨屏移动后会糊
+                            egui_ctx.set_pixels_per_point(scale_factor as f32);
                             gfx.resize(window.inner_size());
                         }
+                        WindowEvent::KeyboardInput { event: key_event, .. } => {
+                            app.handle_key(&key_event, response.consumed);
+                            window.request_redraw();
+                        }
+                        WindowEvent::CursorMoved { position, .. } => {
+                            cursor_pos = position;
+                            if chrome.enabled && !response.consumed {
+                                let icon = chrome::resize_direction_at(&window, cursor_pos)
+                                    .map(chrome::cursor_icon_for)
+                                    .unwrap_or(winit::window::CursorIcon::Default);
+                                window.set_cursor_icon(icon);
+                            }
+                        }
+                        WindowEvent::MouseInput {
+                            state: winit::event::ElementState::Pressed,
+                            button: winit::event::MouseButton::Left,
+                            ..
+                        } => {
+                            if chrome.enabled && !response.consumed {
+                                if let Some(direction) =
+                                    chrome::resize_direction_at(&window, cursor_pos)
+                                {
+                                    let _ = window.drag_resize_window(direction);
+                                }
+                            }
+                        }
                         WindowEvent::RedrawRequested => {
                             let now = Instant::now();
-                            let _dt = now - last_frame;
+                            let dt = now - last_frame;
                             last_frame = now;
+                            elapsed_time += dt.as_secs_f32();
 
                             // 开始 egui 帧
                             let raw_input = egui_winit.take_egui_input(&window);
                             egui_ctx.begin_frame(raw_input);
-                            app.ui(&egui_ctx);
+                            chrome.ui(&egui_ctx, &window);
+                            log_console.ui(&egui_ctx);
+                            app.ui(&egui_ctx, &mut egui_renderer, &gfx.device, &gfx.queue);
                             let full_output = egui_ctx.end_frame();
 
                             // 细分网格
@@ -250,6 +348,9 @@ fn main() {
                                 &screen_desc,
                             );
 
+                            // 先画自定义背景（清屏），egui 再叠加在上面
+                            background.render(&mut encoder, &view, &gfx.queue, gfx.size, elapsed_time);
+
                             // 渲染 UI
                             {
                                 let mut rpass =
@@ -260,12 +361,7 @@ fn main() {
                                                 view: &view,
                                                 resolve_target: None,
                                                 ops: wgpu::Operations {
-                                                    load: wgpu::LoadOp::Clear(wgpu::Color {
-                                                        r: 0.1,
-                                                        g: 0.1,
-                                                        b: 0.12,
-                                                        a: 1.0,
-                                                    }),
+                                                    load: wgpu::LoadOp::Load,
                                                     store: wgpu::StoreOp::Store,
                                                 },
                                             },
@@ -294,55 +390,3 @@ fn main() {
         })
         .expect("事件循环失败");
 }
-
-// 为 egui 安装常见中文字体作为回退；若未找到系统字体，则保持默认设置
-fn install_cjk_fonts(ctx: &egui::Context) {
-    use egui::{FontData, FontDefinitions, FontFamily};
-    let mut fonts = FontDefinitions::default();
-
-    // Windows 常见中文字体路径
-    #[cfg(target_os = "windows")]
-    let candidates: [&str; 8] = [
-        "C:/Windows/Fonts/msyh.ttc", // 微软雅黑
-        "C:/Windows/Fonts/msyh.ttf",
-        "C:/Windows/Fonts/msyhbd.ttc",  // 微软雅黑 Bold
-        "C:/Windows/Fonts/simhei.ttf",  // 黑体
-        "C:/Windows/Fonts/simsun.ttc",  // 宋体
-        "C:/Windows/Fonts/Deng.ttf",    // 等线
-        "C:/Windows/Fonts/msjh.ttc",    // 微软正黑
-        "C:/Windows/Fonts/NsimSun.ttc", // 新宋体
-    ];
-
-    #[cfg(not(target_os = "windows"))]
-    let candidates: [&str; 0] = [];
-
-    let mut loaded_key: Option<String> = None;
-    for path in candidates.iter() {
-        if let Ok(bytes) = std::fs::read(path) {
-            let stem = std::path::Path::new(path)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("cjk");
-            let key = format!("cjk-{}", stem);
-            fonts
-                .font_data
-                .insert(key.clone(), FontData::from_owned(bytes));
-            loaded_key = Some(key);
-            break;
-        }
-    }
-
-    if let Some(key) = loaded_key {
-        fonts
-            .families
-            .entry(FontFamily::Proportional)
-            .or_default()
-            .insert(0, key.clone());
-        fonts
-            .families
-            .entry(FontFamily::Monospace)
-            .or_default()
-            .insert(0, key);
-        ctx.set_fonts(fonts);
-    }
-}